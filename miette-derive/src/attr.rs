@@ -0,0 +1,168 @@
+use proc_macro2::Ident;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    LitStr, Token,
+};
+
+/// Parsed contents of a `#[diagnostic(...)]` container attribute.
+#[derive(Default)]
+pub struct DiagnosticAttr {
+    pub code: Option<syn::Path>,
+    pub help: Option<syn::Expr>,
+    pub url: Option<syn::Expr>,
+}
+
+/// Parsed contents of a `#[snippet(...)]` field attribute.
+pub struct SnippetAttr {
+    pub source_field: Ident,
+    pub message: Option<syn::Expr>,
+}
+
+/// Parsed contents of a `#[suggestion(...)]` field attribute, applied to
+/// a `SourceSpan` field alongside `replacement` text and how safe it is
+/// to apply.
+pub struct SuggestionAttr {
+    pub source_field: Ident,
+    pub replacement: syn::Expr,
+    pub applicability: Ident,
+}
+
+/// Parsed contents of a `#[highlight(...)]` field attribute.
+///
+/// `primary` marks this span as the diagnostic's actual location (as
+/// opposed to supporting context) -- see `miette::HighlightKind` in the
+/// main crate for how printers use this.
+pub struct HighlightAttr {
+    pub snippet_field: Ident,
+    pub label: Option<syn::Expr>,
+    pub primary: bool,
+}
+
+mod kw {
+    syn::custom_keyword!(code);
+    syn::custom_keyword!(help);
+    syn::custom_keyword!(url);
+    syn::custom_keyword!(message);
+    syn::custom_keyword!(label);
+    syn::custom_keyword!(primary);
+    syn::custom_keyword!(replacement);
+    syn::custom_keyword!(applicability);
+}
+
+enum DiagnosticArg {
+    Code(syn::Path),
+    Help(syn::Expr),
+    Url(syn::Expr),
+}
+
+impl Parse for DiagnosticArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::code) {
+            input.parse::<kw::code>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(DiagnosticArg::Code(content.parse()?))
+        } else if lookahead.peek(kw::help) {
+            input.parse::<kw::help>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(DiagnosticArg::Help(content.parse()?))
+        } else if lookahead.peek(kw::url) {
+            input.parse::<kw::url>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(DiagnosticArg::Url(content.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl Parse for DiagnosticAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let args = Punctuated::<DiagnosticArg, Token![,]>::parse_terminated(input)?;
+        let mut attr = DiagnosticAttr::default();
+        for arg in args {
+            match arg {
+                DiagnosticArg::Code(code) => attr.code = Some(code),
+                DiagnosticArg::Help(help) => attr.help = Some(help),
+                DiagnosticArg::Url(url) => attr.url = Some(url),
+            }
+        }
+        Ok(attr)
+    }
+}
+
+impl Parse for SnippetAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let source_field: Ident = input.parse()?;
+        let mut message = None;
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            if input.peek(kw::message) {
+                input.parse::<kw::message>()?;
+                let content;
+                syn::parenthesized!(content in input);
+                message = Some(content.parse()?);
+            }
+        }
+        Ok(SnippetAttr {
+            source_field,
+            message,
+        })
+    }
+}
+
+impl Parse for SuggestionAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let source_field: Ident = input.parse()?;
+        let mut replacement = None;
+        let mut applicability = Ident::new("Unspecified", proc_macro2::Span::call_site());
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            if input.peek(kw::replacement) {
+                input.parse::<kw::replacement>()?;
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+                replacement = Some(syn::parse_quote!(#value));
+            } else if input.peek(kw::applicability) {
+                input.parse::<kw::applicability>()?;
+                input.parse::<Token![=]>()?;
+                applicability = input.parse()?;
+            }
+        }
+        Ok(SuggestionAttr {
+            source_field,
+            replacement: replacement
+                .ok_or_else(|| input.error("expected `replacement = \"...\"`"))?,
+            applicability,
+        })
+    }
+}
+
+impl Parse for HighlightAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let snippet_field: Ident = input.parse()?;
+        let mut label = None;
+        let mut primary = false;
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            if input.peek(kw::primary) {
+                input.parse::<kw::primary>()?;
+                primary = true;
+            } else if input.peek(kw::label) {
+                input.parse::<kw::label>()?;
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+                label = Some(syn::parse_quote!(#value));
+            }
+        }
+        Ok(HighlightAttr {
+            snippet_field,
+            label,
+            primary,
+        })
+    }
+}
@@ -0,0 +1,190 @@
+//! The `#[derive(Diagnostic)]` proc macro backing the main `miette` crate.
+
+mod attr;
+
+use attr::{DiagnosticAttr, HighlightAttr, SnippetAttr, SuggestionAttr};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Fields};
+
+/// Derives `miette::Diagnostic` for a struct, reading its shape from
+/// `#[diagnostic(...)]`, `#[snippet(...)]`, `#[highlight(...)]` and
+/// `#[suggestion(...)]` attributes.
+#[proc_macro_derive(
+    Diagnostic,
+    attributes(diagnostic, snippet, highlight, suggestion)
+)]
+pub fn derive_diagnostic(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    diagnostic_impl(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn diagnostic_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut diag_attr = DiagnosticAttr::default();
+    for attr in &input.attrs {
+        if attr.path.is_ident("diagnostic") {
+            diag_attr = attr.parse_args()?;
+        }
+    }
+
+    let no_fields = Punctuated::new();
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            // Unit structs (e.g. `struct MyBad;`) have no snippets,
+            // highlights or suggestions to read -- just the container
+            // `#[diagnostic(...)]` attribute.
+            Fields::Unit => &no_fields,
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Diagnostic can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Diagnostic can only be derived for structs",
+            ))
+        }
+    };
+
+    // Every `#[snippet(...)]` field becomes one `Snippet`, picking up any
+    // `#[highlight(...)]` fields that point at it via `snippet_field`.
+    let mut snippet_exprs = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.as_ref().unwrap();
+        for attr in &field.attrs {
+            if attr.path.is_ident("snippet") {
+                let snippet: SnippetAttr = attr.parse_args()?;
+                let source_field = &snippet.source_field;
+                let message = match &snippet.message {
+                    Some(expr) => quote! { Some(format!(#expr)) },
+                    None => quote! { None },
+                };
+
+                let mut highlight_exprs = Vec::new();
+                for hfield in fields {
+                    let hfield_ident = hfield.ident.as_ref().unwrap();
+                    for hattr in &hfield.attrs {
+                        if hattr.path.is_ident("highlight") {
+                            let highlight: HighlightAttr = hattr.parse_args()?;
+                            if &highlight.snippet_field != field_ident {
+                                continue;
+                            }
+                            let label = match &highlight.label {
+                                Some(expr) => quote! { Some(format!(#expr)) },
+                                None => quote! { None },
+                            };
+                            let ctor = if highlight.primary {
+                                quote! { miette::Highlight::primary }
+                            } else {
+                                quote! { miette::Highlight::new }
+                            };
+                            highlight_exprs.push(quote! {
+                                #ctor(self.#hfield_ident, #label)
+                            });
+                        }
+                    }
+                }
+
+                snippet_exprs.push(quote! {
+                    miette::Snippet {
+                        source: &self.#source_field,
+                        message: #message,
+                        context: self.#field_ident,
+                        highlights: vec![#(#highlight_exprs),*],
+                    }
+                });
+            }
+        }
+    }
+
+    let code_impl = match &diag_attr.code {
+        Some(code) => {
+            let code_str = quote!(#code).to_string().replace(' ', "");
+            quote! {
+                fn code(&self) -> Option<Box<dyn std::fmt::Display + '_>> {
+                    Some(Box::new(#code_str))
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let help_impl = match &diag_attr.help {
+        Some(expr) => quote! {
+            fn help(&self) -> Option<Box<dyn std::fmt::Display + '_>> {
+                Some(Box::new(format!(#expr)))
+            }
+        },
+        None => quote! {},
+    };
+
+    let url_impl = match &diag_attr.url {
+        Some(expr) => quote! {
+            fn url(&self) -> Option<Box<dyn std::fmt::Display + '_>> {
+                Some(Box::new(format!(#expr)))
+            }
+        },
+        None => quote! {},
+    };
+
+    let snippets_impl = if snippet_exprs.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn snippets(&self) -> Option<Box<dyn Iterator<Item = miette::Snippet<'_>> + '_>> {
+                Some(Box::new(vec![#(#snippet_exprs),*].into_iter()))
+            }
+        }
+    };
+
+    // Each `#[suggestion(...)]` field produces one `Suggestion`.
+    let mut suggestion_exprs = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.as_ref().unwrap();
+        for attr in &field.attrs {
+            if attr.path.is_ident("suggestion") {
+                let suggestion: SuggestionAttr = attr.parse_args()?;
+                let source_field = &suggestion.source_field;
+                let replacement = &suggestion.replacement;
+                let applicability = &suggestion.applicability;
+                suggestion_exprs.push(quote! {
+                    miette::Suggestion::new(
+                        &self.#source_field,
+                        self.#field_ident,
+                        format!(#replacement),
+                        miette::Applicability::#applicability,
+                    )
+                });
+            }
+        }
+    }
+
+    let suggestions_impl = if suggestion_exprs.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn suggestions(&self) -> Vec<miette::Suggestion<'_>> {
+                vec![#(#suggestion_exprs),*]
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics miette::Diagnostic for #ident #ty_generics #where_clause {
+            #code_impl
+            #help_impl
+            #url_impl
+            #snippets_impl
+            #suggestions_impl
+        }
+    })
+}
@@ -0,0 +1,20 @@
+use thiserror::Error;
+
+/// Error type for miette's own fallible operations (mostly rendering).
+#[derive(Debug, Error)]
+pub enum MietteError {
+    /// Wrapper around [`std::io::Error`], returned from printers when
+    /// writing to the output fails.
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+
+    /// Wrapper around [`std::fmt::Error`], returned from printers when
+    /// writing into a `std::fmt::Write` sink (e.g. a `String`) fails.
+    #[error(transparent)]
+    FmtError(#[from] std::fmt::Error),
+
+    /// Returned when a [`crate::SourceSpan`] is out of bounds for the
+    /// source it's being used to slice.
+    #[error("Requested out-of-bounds span")]
+    OutOfBounds,
+}
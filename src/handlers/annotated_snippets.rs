@@ -0,0 +1,119 @@
+use std::fmt::Write;
+
+use annotate_snippets::{
+    display_list::DisplayList,
+    snippet::{Annotation, AnnotationType, Slice, Snippet as AnnotatedSnippet, SourceAnnotation},
+};
+
+use crate::{Diagnostic, HighlightKind, MietteError};
+
+/// Renders a [`Diagnostic`] through the `annotate-snippets` crate instead
+/// of miette's own box-drawing layout, for users who already standardize
+/// on that crate's look-and-feel (and its line-folding behavior for very
+/// long snippets).
+///
+/// Each `#[highlight]` becomes a `SourceAnnotation`: primary highlights
+/// map to `AnnotationType::Error`, secondary ones to `AnnotationType::Info`.
+/// The report's title is the diagnostic's own message; a snippet's
+/// `message(...)` is not rendered by this printer.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AnnotatedSnippetsPrinter;
+
+impl AnnotatedSnippetsPrinter {
+    /// Renders `diagnostic` into `out`.
+    pub fn render_report(
+        &self,
+        out: &mut impl Write,
+        diagnostic: &dyn Diagnostic,
+    ) -> Result<(), MietteError> {
+        let message = diagnostic.to_string();
+        let code_text = diagnostic.code().map(|c| c.to_string());
+        let footer_help = diagnostic.help().map(|help| help.to_string());
+
+        let mut slices = Vec::new();
+        if let Some(snippets) = diagnostic.snippets() {
+            for snippet in snippets {
+                let source = snippet
+                    .source
+                    .read_span(&snippet.context)
+                    .unwrap_or_default();
+                let origin = snippet.source.name().to_string();
+                let line_start = line_number(snippet.source.inner(), snippet.context.offset());
+
+                let annotations = snippet
+                    .highlights
+                    .iter()
+                    .map(|highlight| {
+                        (
+                            (
+                                highlight.span.offset() - snippet.context.offset(),
+                                highlight.span.offset() - snippet.context.offset()
+                                    + highlight.span.len(),
+                            ),
+                            highlight.label.clone().unwrap_or_default(),
+                            match highlight.kind {
+                                HighlightKind::Primary => AnnotationType::Error,
+                                HighlightKind::Secondary => AnnotationType::Info,
+                            },
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                slices.push((origin, source.to_string(), line_start, annotations));
+            }
+        }
+
+        // `annotate_snippets::Snippet` borrows everything it wraps, so we
+        // build the owned pieces above first and the borrowing views here.
+        let built_slices: Vec<Slice<'_>> = slices
+            .iter()
+            .map(|(origin, source, line_start, annotations)| Slice {
+                source,
+                line_start: *line_start,
+                origin: Some(origin),
+                annotations: annotations
+                    .iter()
+                    .map(|(range, label, annotation_type)| SourceAnnotation {
+                        range: *range,
+                        label,
+                        annotation_type: *annotation_type,
+                    })
+                    .collect(),
+                fold: true,
+            })
+            .collect();
+
+        let annotated = AnnotatedSnippet {
+            title: Some(Annotation {
+                id: code_text.as_deref(),
+                label: Some(&message),
+                annotation_type: AnnotationType::Error,
+            }),
+            footer: footer_help
+                .as_deref()
+                .map(|help| {
+                    vec![Annotation {
+                        id: None,
+                        label: Some(help),
+                        annotation_type: AnnotationType::Help,
+                    }]
+                })
+                .unwrap_or_default(),
+            slices: built_slices,
+            opt: Default::default(),
+        };
+
+        writeln!(out, "{}", DisplayList::from(annotated))?;
+        Ok(())
+    }
+}
+
+/// The 1-indexed line that byte `offset` of `text` falls on, found by
+/// counting newlines up to it (mirrors `GraphicalReportPrinter::line_and_column`).
+fn line_number(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    1 + text[..offset].matches('\n').count()
+}
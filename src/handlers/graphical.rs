@@ -0,0 +1,604 @@
+use std::fmt::Write;
+
+use crate::unicode_width::{char_width, str_width};
+use crate::{
+    Applicability, Diagnostic, GraphicalTheme, Highlight, HighlightKind, MietteError, Severity,
+    Snippet, Suggestion,
+};
+
+/// Renders a [`Diagnostic`] as a human-friendly, boxed-and-underlined
+/// report, in the style of rustc's default error output.
+#[derive(Clone, Debug)]
+pub struct GraphicalReportPrinter {
+    theme: GraphicalTheme,
+    linkify_code: bool,
+    tab_width: usize,
+}
+
+/// A multi-line highlight's 1-indexed start/end lines, alongside the
+/// highlight itself -- see [`GraphicalReportPrinter::multiline_rails`].
+struct MultilineRail<'a> {
+    start_line: usize,
+    end_line: usize,
+    highlight: &'a Highlight,
+}
+
+impl GraphicalReportPrinter {
+    /// Creates a printer using the default (unicode, colored) theme.
+    pub fn new() -> Self {
+        Self::new_themed(GraphicalTheme::default())
+    }
+
+    /// Creates a printer using a specific [`GraphicalTheme`].
+    pub fn new_themed(theme: GraphicalTheme) -> Self {
+        Self {
+            theme,
+            linkify_code: true,
+            tab_width: 4,
+        }
+    }
+
+    /// Disables turning `code(...)` into a clickable "click for details"
+    /// link to the diagnostic's `url(...)`.
+    pub fn without_code_linking(mut self) -> Self {
+        self.linkify_code = false;
+        self
+    }
+
+    /// Sets how many display columns a `\t` in source text expands to
+    /// (default 4). Source lines and their underlines are expanded with
+    /// the same tab stops, so carets stay aligned under the characters
+    /// they point at no matter how the source indents.
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Renders `diagnostic` into `out`.
+    pub fn render_report(
+        &self,
+        out: &mut impl Write,
+        diagnostic: &dyn Diagnostic,
+    ) -> Result<(), MietteError> {
+        self.render_header(out, diagnostic)?;
+        writeln!(out)?;
+        self.render_message(out, diagnostic)?;
+        if let Some(snippets) = diagnostic.snippets() {
+            for snippet in snippets {
+                writeln!(out)?;
+                self.render_snippet(out, &snippet)?;
+            }
+        }
+        let suggestions = diagnostic.suggestions();
+        if !suggestions.is_empty() {
+            writeln!(out)?;
+            self.render_suggestions(out, &suggestions)?;
+        }
+        if let Some(help) = diagnostic.help() {
+            writeln!(out)?;
+            writeln!(out, "    ‽ {}", help)?;
+        }
+        Ok(())
+    }
+
+    /// Renders each suggestion either as an inline `a -> b` replacement
+    /// (any single-line fix, whatever its applicability) or as a
+    /// rustc-style `-`/`+` diff block against the affected source lines
+    /// (anything spanning more than one line).
+    fn render_suggestions(
+        &self,
+        out: &mut impl Write,
+        suggestions: &[Suggestion<'_>],
+    ) -> Result<(), MietteError> {
+        let chars = &self.theme.characters;
+        for suggestion in suggestions {
+            let old = suggestion.source.read_span(&suggestion.span)?;
+            let applicability = match suggestion.applicability {
+                Applicability::MachineApplicable => "machine-applicable",
+                Applicability::MaybeIncorrect => "maybe incorrect",
+                Applicability::HasPlaceholders => "has placeholders",
+                Applicability::Unspecified => "unspecified",
+            };
+            if old.contains('\n') || suggestion.replacement.contains('\n') {
+                writeln!(out, "    {} suggestion ({}):", chars.advice, applicability)?;
+                for line in old.lines() {
+                    writeln!(out, "    - {}", self.expand_tabs(line))?;
+                }
+                for line in suggestion.replacement.lines() {
+                    writeln!(out, "    + {}", self.expand_tabs(line))?;
+                }
+            } else {
+                writeln!(
+                    out,
+                    "    {} suggestion ({}): `{}` -> `{}`",
+                    chars.advice, applicability, old, suggestion.replacement
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn render_header(
+        &self,
+        out: &mut impl Write,
+        diagnostic: &dyn Diagnostic,
+    ) -> Result<(), MietteError> {
+        let chars = &self.theme.characters;
+        if let Some(code) = diagnostic.code() {
+            let label = if self.linkify_code {
+                if let Some(url) = diagnostic.url() {
+                    format!("[{}] (click for details: {})", code, url)
+                } else {
+                    format!("[{}]", code)
+                }
+            } else {
+                format!("[{}]", code)
+            };
+            let bar: String = std::iter::repeat_n(chars.hbar, 4).collect();
+            let fill: String = std::iter::repeat_n(chars.hbar, 20).collect();
+            writeln!(out, "{}{}{}", bar, label, fill)?;
+        }
+        Ok(())
+    }
+
+    fn render_message(
+        &self,
+        out: &mut impl Write,
+        diagnostic: &dyn Diagnostic,
+    ) -> Result<(), MietteError> {
+        let chars = &self.theme.characters;
+        let line = format!("{} {}", chars.error, diagnostic);
+        writeln!(out, "    {}", self.color_severity(diagnostic.severity(), &line))?;
+        Ok(())
+    }
+
+    /// Wraps `text` in the ANSI color code for `severity`, if
+    /// [`GraphicalTheme::with_color`] is set -- otherwise returns it
+    /// unchanged.
+    fn color_severity(&self, severity: Severity, text: &str) -> String {
+        if !self.theme.with_color {
+            return text.to_string();
+        }
+        let code = match severity {
+            Severity::Error => "31",
+            Severity::Warning => "33",
+            Severity::Advice => "36",
+        };
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    }
+
+    /// Wraps `text` in the ANSI color code for `kind`, if
+    /// [`GraphicalTheme::with_color`] is set -- otherwise returns it
+    /// unchanged. Mirrors [`Self::color_severity`], but for the
+    /// primary/secondary distinction on individual highlights.
+    fn color_highlight(&self, kind: HighlightKind, text: &str) -> String {
+        if !self.theme.with_color {
+            return text.to_string();
+        }
+        let code = match kind {
+            HighlightKind::Primary => "1;31",
+            HighlightKind::Secondary => "36",
+        };
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    }
+
+    fn render_snippet(
+        &self,
+        out: &mut impl Write,
+        snippet: &Snippet<'_>,
+    ) -> Result<(), MietteError> {
+        let chars = &self.theme.characters;
+        let (line, col) = self.line_and_column(snippet, snippet.context.offset());
+        let header = if let Some(message) = &snippet.message {
+            format!(
+                "[{}:{}:{}] {}:",
+                snippet.source.name(),
+                line,
+                col,
+                message
+            )
+        } else {
+            format!("[{}:{}:{}]", snippet.source.name(), line, col)
+        };
+        writeln!(
+            out,
+            "   {}{}{}{}{}",
+            chars.top_left, chars.hbar, chars.hbar, chars.hbar, header
+        )?;
+
+        let context_text = snippet
+            .source
+            .read_span(&snippet.context)
+            .unwrap_or_default();
+        let base_line = line;
+        let rails = self.multiline_rails(snippet);
+        let rail_width = self.rail_width(&rails);
+        for (i, line_text) in context_text.lines().enumerate() {
+            let line_no = base_line + i;
+            let active = self.active_rails(&rails, line_no);
+            if rail_width > 0 {
+                let deco = self.rail_decoration(&active, line_no, rail_width);
+                writeln!(
+                    out,
+                    "{:>2} {} {} {}",
+                    line_no,
+                    chars.vbar,
+                    deco,
+                    self.expand_tabs(line_text)
+                )?;
+            } else {
+                writeln!(out, "{:>2} {} {}", line_no, chars.vbar, self.expand_tabs(line_text))?;
+            }
+            let pad = " ".repeat(rail_width + if rail_width > 0 { 1 } else { 0 });
+            if let Some(row) = self.underline_row_for_line(snippet, line_no) {
+                writeln!(out, "   {} {}{}", chars.vbar_break, pad, row)?;
+                if let Some(label_rows) = self.label_rows_for_line(snippet, line_no) {
+                    for row in label_rows {
+                        writeln!(out, "   {} {}{}", chars.vbar_break, pad, row)?;
+                    }
+                }
+            }
+            for rail in active
+                .iter()
+                .filter(|r| r.end_line == line_no && r.highlight.label.is_some())
+            {
+                let label_row = self.rail_label_row(&active, rail, rail_width);
+                writeln!(out, "   {} {}", chars.vbar_break, label_row)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// A multi-line highlight's 1-indexed start/end lines, alongside the
+    /// highlight itself -- the data a "rail" (one of the `│`/`╭`/`├`/`╰`
+    /// vertical connectors drawn to the left of multi-line source) is
+    /// drawn from.
+    fn multiline_rails<'a>(&self, snippet: &'a Snippet<'_>) -> Vec<MultilineRail<'a>> {
+        let mut rails: Vec<_> = snippet
+            .highlights
+            .iter()
+            .filter_map(|highlight| {
+                let (start_line, end_line) = self.highlight_line_range(snippet, highlight);
+                (end_line > start_line).then_some(MultilineRail {
+                    start_line,
+                    end_line,
+                    highlight,
+                })
+            })
+            .collect();
+        rails.sort_by_key(|rail| rail.start_line);
+        rails
+    }
+
+    /// The first and last 1-indexed lines a highlight's span touches.
+    fn highlight_line_range(&self, snippet: &Snippet<'_>, highlight: &Highlight) -> (usize, usize) {
+        let start = self.line_and_column(snippet, highlight.span.offset()).0;
+        let last_byte = highlight
+            .span
+            .offset()
+            .saturating_add(highlight.span.len().saturating_sub(1));
+        let end = self.line_and_column(snippet, last_byte).0;
+        (start, end)
+    }
+
+    /// How many display columns the rail gutter needs: one per rail that's
+    /// simultaneously open at some line, plus one for the connecting hbar
+    /// and one for the `▶` arrow. Zero if there are no multi-line
+    /// highlights in this snippet at all, so single-line-only snippets are
+    /// unaffected.
+    fn rail_width(&self, rails: &[MultilineRail<'_>]) -> usize {
+        let Some(max_line) = rails.iter().map(|r| r.end_line).max() else {
+            return 0;
+        };
+        let min_line = rails.iter().map(|r| r.start_line).min().unwrap_or(max_line);
+        let max_depth = (min_line..=max_line)
+            .map(|line| {
+                rails
+                    .iter()
+                    .filter(|r| r.start_line <= line && line <= r.end_line)
+                    .count()
+            })
+            .max()
+            .unwrap_or(0);
+        max_depth + 2
+    }
+
+    /// The rails open at `line_no`, in the order they were opened.
+    fn active_rails<'a, 'b>(
+        &self,
+        rails: &'b [MultilineRail<'a>],
+        line_no: usize,
+    ) -> Vec<&'b MultilineRail<'a>> {
+        rails
+            .iter()
+            .filter(|r| r.start_line <= line_no && line_no <= r.end_line)
+            .collect()
+    }
+
+    /// Renders one line's rail gutter: a `│` for every rail that's merely
+    /// passing through, and for any rail that starts or ends on this line
+    /// its own marker (`╭` starting, `├` ending with a label row still to
+    /// come, `╰` ending with nothing further) -- a line can be the
+    /// start/end of more than one rail at once, and each gets its own
+    /// marker. Followed by an arrow-tipped hbar run if any rail had an
+    /// event on this line, or blank padding if every open rail is just
+    /// passing through. The hbar run uses the same `underline_primary` /
+    /// `underline_secondary` glyph a single-line highlight's underline
+    /// would, so a primary multi-line span reads distinctly from a
+    /// secondary one (primary wins if both occur on the same line), and
+    /// the whole event is colored by [`Self::color_highlight`].
+    fn rail_decoration(&self, active: &[&MultilineRail<'_>], line_no: usize, width: usize) -> String {
+        let chars = &self.theme.characters;
+        let mut cells: Vec<(char, Option<HighlightKind>)> = Vec::new();
+        let mut any_event = false;
+        let mut event_kind = HighlightKind::Secondary;
+        for rail in active {
+            let is_event = rail.start_line == line_no || rail.end_line == line_no;
+            if rail.start_line == line_no {
+                cells.push((chars.top_left, Some(rail.highlight.kind)));
+            } else if rail.end_line == line_no {
+                let marker = if rail.highlight.label.is_some() {
+                    chars.mid_left
+                } else {
+                    chars.bottom_left
+                };
+                cells.push((marker, Some(rail.highlight.kind)));
+            } else {
+                cells.push((chars.vbar, None));
+            }
+            if is_event {
+                any_event = true;
+                if rail.highlight.is_primary() {
+                    event_kind = HighlightKind::Primary;
+                }
+            }
+        }
+        if any_event {
+            let fill = match event_kind {
+                HighlightKind::Primary => chars.underline_primary,
+                HighlightKind::Secondary => chars.underline_secondary,
+            };
+            let hbars = width.saturating_sub(1).saturating_sub(cells.len());
+            for _ in 0..hbars {
+                cells.push((fill, Some(event_kind)));
+            }
+            cells.push((chars.arrow, Some(event_kind)));
+        } else {
+            while cells.len() < width {
+                cells.push((' ', None));
+            }
+        }
+        self.render_cells(&cells)
+    }
+
+    /// The dedicated row drawn below a multi-line highlight's last line,
+    /// carrying its label -- mirrors a single-line highlight's label row,
+    /// but closes off the rail gutter instead of an inline underline.
+    fn rail_label_row(
+        &self,
+        active: &[&MultilineRail<'_>],
+        ending: &MultilineRail<'_>,
+        width: usize,
+    ) -> String {
+        let chars = &self.theme.characters;
+        let mut cells: Vec<(char, Option<HighlightKind>)> = active
+            .iter()
+            .filter(|r| r.end_line > ending.end_line)
+            .map(|_| (chars.vbar, None))
+            .collect();
+        let label_width = width + 2;
+        let hbars = label_width.saturating_sub(1).saturating_sub(cells.len());
+        let fill = match ending.highlight.kind {
+            HighlightKind::Primary => chars.underline_primary,
+            HighlightKind::Secondary => chars.underline_secondary,
+        };
+        cells.push((chars.bottom_left, Some(ending.highlight.kind)));
+        for _ in 0..hbars {
+            cells.push((fill, Some(ending.highlight.kind)));
+        }
+        let mut row = self.render_cells(&cells);
+        write!(row, " {}", ending.highlight.label.as_ref().unwrap()).ok();
+        row
+    }
+
+    /// Renders a sequence of glyphs, each optionally tagged with the
+    /// [`HighlightKind`] that should color it, collapsing contiguous runs
+    /// of the same kind into a single colored span via
+    /// [`Self::color_highlight`].
+    fn render_cells(&self, cells: &[(char, Option<HighlightKind>)]) -> String {
+        let mut out = String::new();
+        let mut i = 0;
+        while i < cells.len() {
+            let kind = cells[i].1;
+            let mut j = i + 1;
+            while j < cells.len() && cells[j].1 == kind {
+                j += 1;
+            }
+            let segment: String = cells[i..j].iter().map(|(c, _)| *c).collect();
+            match kind {
+                Some(k) => out.push_str(&self.color_highlight(k, &segment)),
+                None => out.push_str(&segment),
+            }
+            i = j;
+        }
+        out
+    }
+
+    /// Computes the 1-indexed line, and *display* column (with `\t`
+    /// expanded to the next tab stop) of a byte offset into a snippet's
+    /// source.
+    fn line_and_column(&self, snippet: &Snippet<'_>, offset: usize) -> (usize, usize) {
+        let text = snippet.source.inner();
+        let mut line = 1;
+        let mut col = 1;
+        let mut offset = offset.min(text.len());
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        for ch in text[..offset].chars() {
+            if ch == '\n' {
+                line += 1;
+                col = 1;
+            } else if ch == '\t' {
+                col = self.next_tab_stop(col);
+            } else {
+                col += char_width(ch);
+            }
+        }
+        (line, col)
+    }
+
+    /// The width, in display columns, of the text covered by a highlight
+    /// -- not its byte length, which would put the underline in the
+    /// wrong place for wide (CJK, emoji) or zero-width (combining mark)
+    /// characters.
+    fn highlight_display_width(&self, snippet: &Snippet<'_>, highlight: &Highlight) -> usize {
+        snippet
+            .source
+            .read_span(&highlight.span)
+            .map(str_width)
+            .unwrap_or(0)
+            .max(1)
+    }
+
+    /// The 1-indexed display column a tab at `col` jumps to.
+    fn next_tab_stop(&self, col: usize) -> usize {
+        let width = self.tab_width.max(1);
+        let zero_indexed = col - 1;
+        zero_indexed - (zero_indexed % width) + width + 1
+    }
+
+    /// Expands `\t` characters in a source line to spaces, using the same
+    /// tab stops as [`Self::line_and_column`], so the rendered line and
+    /// its underline row stay aligned.
+    fn expand_tabs(&self, line: &str) -> String {
+        let mut out = String::new();
+        let mut col = 1;
+        for ch in line.chars() {
+            if ch == '\t' {
+                let next = self.next_tab_stop(col);
+                for _ in col..next {
+                    out.push(' ');
+                }
+                col = next;
+            } else {
+                out.push(ch);
+                col += char_width(ch);
+            }
+        }
+        out
+    }
+
+    /// Highlights entirely contained within the given (1-indexed) line.
+    /// Highlights spanning more than one line are drawn separately, as
+    /// rail gutters -- see [`Self::multiline_rails`].
+    fn highlights_on_line<'a>(
+        &self,
+        snippet: &'a Snippet<'_>,
+        line_no: usize,
+    ) -> Vec<&'a Highlight> {
+        snippet
+            .highlights
+            .iter()
+            .filter(|h| self.highlight_line_range(snippet, h) == (line_no, line_no))
+            .collect()
+    }
+
+    /// The underline's display width and the 0-indexed offset (within
+    /// that width) of its `┬`/`╰` marker: the middle of the underline for
+    /// a labeled span, or its only column for an empty span.
+    fn underline_shape(&self, snippet: &Snippet<'_>, highlight: &Highlight) -> (usize, usize) {
+        if highlight.span.is_empty() {
+            (1, 0)
+        } else {
+            let width = self.highlight_display_width(snippet, highlight);
+            (width, width / 2)
+        }
+    }
+
+    /// The 0-indexed display column the `╰──` connector for a labeled
+    /// highlight points at: the middle of its underline (or the
+    /// highlight's own column, for an empty span's single `┬`).
+    fn marker_column(&self, snippet: &Snippet<'_>, highlight: &Highlight) -> usize {
+        let (_, col) = self.line_and_column(snippet, highlight.span.offset());
+        let (_, mid) = self.underline_shape(snippet, highlight);
+        col - 1 + mid
+    }
+
+    fn underline_row_for_line(&self, snippet: &Snippet<'_>, line_no: usize) -> Option<String> {
+        let highlights = self.highlights_on_line(snippet, line_no);
+        if highlights.is_empty() {
+            return None;
+        }
+        let chars = &self.theme.characters;
+        // Each segment is colored as it's appended -- `visual_len` (not
+        // the colored string's byte/char count, which would include ANSI
+        // escapes) is what keeps later highlights on this same line
+        // correctly aligned.
+        let mut row = String::new();
+        let mut visual_len = 0usize;
+        for highlight in &highlights {
+            let (_, col) = self.line_and_column(snippet, highlight.span.offset());
+            while visual_len < col - 1 {
+                row.push(' ');
+                visual_len += 1;
+            }
+            let underline_char = match highlight.kind {
+                HighlightKind::Primary => chars.underline_primary,
+                HighlightKind::Secondary => chars.underline_secondary,
+            };
+            let mut segment = String::new();
+            if highlight.span.is_empty() {
+                segment.push(chars.underbar);
+            } else if highlight.label.is_some() {
+                let width = self.highlight_display_width(snippet, highlight);
+                let mid = width / 2;
+                for i in 0..width {
+                    segment.push(if i == mid { chars.underbar } else { underline_char });
+                }
+            } else {
+                for _ in 0..self.highlight_display_width(snippet, highlight) {
+                    segment.push(underline_char);
+                }
+            }
+            visual_len += segment.chars().count();
+            row.push_str(&self.color_highlight(highlight.kind, &segment));
+        }
+        Some(row)
+    }
+
+    fn label_rows_for_line(&self, snippet: &Snippet<'_>, line_no: usize) -> Option<Vec<String>> {
+        // Primary labels are described before secondary ones, matching
+        // the order a compiler points out "here's the actual problem"
+        // before "...and here's some context".
+        let mut highlights = self.highlights_on_line(snippet, line_no);
+        highlights.sort_by_key(|h| !h.is_primary());
+        let chars = &self.theme.characters;
+        let mut rows = Vec::new();
+        for highlight in highlights {
+            if let Some(label) = &highlight.label {
+                let col = self.marker_column(snippet, highlight);
+                let (width, mid) = self.underline_shape(snippet, highlight);
+                let mut row = String::new();
+                while row.chars().count() < col {
+                    row.push(' ');
+                }
+                let mut connector = String::new();
+                connector.push(chars.underline_left);
+                for _ in 0..(width - mid) {
+                    connector.push(chars.hbar);
+                }
+                row.push_str(&self.color_highlight(highlight.kind, &connector));
+                write!(row, " {}", label).ok();
+                rows.push(row);
+            }
+        }
+        Some(rows)
+    }
+}
+
+impl Default for GraphicalReportPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
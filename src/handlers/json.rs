@@ -0,0 +1,130 @@
+use std::fmt::Write;
+
+use crate::{Diagnostic, MietteError};
+
+/// Renders a [`Diagnostic`] as a single structured JSON object, so
+/// editors and LSP servers can consume miette diagnostics programmatically
+/// instead of scraping the human-formatted graphical or narrated output --
+/// the same role rustc's `--error-format=json` emitter plays next to its
+/// human emitter.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonReportPrinter;
+
+impl JsonReportPrinter {
+    /// Renders `diagnostic` into `out` as one JSON object, with a trailing
+    /// newline.
+    pub fn render_report(
+        &self,
+        out: &mut impl Write,
+        diagnostic: &dyn Diagnostic,
+    ) -> Result<(), MietteError> {
+        let mut json = String::from("{");
+        write!(json, "\"message\":{}", to_json_string(&diagnostic.to_string()))?;
+        write!(json, ",\"severity\":{}", to_json_string(severity_str(diagnostic)))?;
+        if let Some(code) = diagnostic.code() {
+            write!(json, ",\"code\":{}", to_json_string(&code.to_string()))?;
+        } else {
+            write!(json, ",\"code\":null")?;
+        }
+        if let Some(help) = diagnostic.help() {
+            write!(json, ",\"help\":{}", to_json_string(&help.to_string()))?;
+        } else {
+            write!(json, ",\"help\":null")?;
+        }
+        if let Some(url) = diagnostic.url() {
+            write!(json, ",\"url\":{}", to_json_string(&url.to_string()))?;
+        } else {
+            write!(json, ",\"url\":null")?;
+        }
+
+        write!(json, ",\"snippets\":[")?;
+        if let Some(snippets) = diagnostic.snippets() {
+            let mut first = true;
+            for snippet in snippets {
+                if !first {
+                    write!(json, ",")?;
+                }
+                first = false;
+                write!(json, "{{")?;
+                write!(json, "\"source\":{}", to_json_string(snippet.source.name()))?;
+                match &snippet.message {
+                    Some(message) => write!(json, ",\"message\":{}", to_json_string(message))?,
+                    None => write!(json, ",\"message\":null")?,
+                }
+                write!(json, ",\"highlights\":[")?;
+                let mut first_highlight = true;
+                for highlight in &snippet.highlights {
+                    if !first_highlight {
+                        write!(json, ",")?;
+                    }
+                    first_highlight = false;
+                    let (line, column) = line_and_column(snippet.source.inner(), highlight.span.offset());
+                    write!(
+                        json,
+                        "{{\"byte_offset\":{},\"length\":{},\"line\":{},\"column\":{},\"label\":{}}}",
+                        highlight.span.offset(),
+                        highlight.span.len(),
+                        line,
+                        column,
+                        match &highlight.label {
+                            Some(label) => to_json_string(label),
+                            None => "null".to_string(),
+                        }
+                    )?;
+                }
+                write!(json, "]}}")?;
+            }
+        }
+        write!(json, "]}}")?;
+
+        writeln!(out, "{}", json)?;
+        Ok(())
+    }
+}
+
+fn severity_str(diagnostic: &dyn Diagnostic) -> &'static str {
+    match diagnostic.severity() {
+        crate::Severity::Advice => "advice",
+        crate::Severity::Warning => "warning",
+        crate::Severity::Error => "error",
+    }
+}
+
+fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut col = 1;
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    for ch in text[..offset].chars() {
+        if ch == '\n' {
+            line += 1;
+            col = 1;
+        } else {
+            col += 1;
+        }
+    }
+    (line, col)
+}
+
+/// Minimal JSON string escaping -- miette doesn't otherwise depend on a
+/// JSON library, and diagnostic text is simple enough not to need one.
+fn to_json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                write!(out, "\\u{:04x}", c as u32).ok();
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,13 @@
+#[cfg(feature = "annotate-snippets")]
+mod annotated_snippets;
+mod graphical;
+mod json;
+mod narratable;
+mod theme;
+
+#[cfg(feature = "annotate-snippets")]
+pub use annotated_snippets::AnnotatedSnippetsPrinter;
+pub use graphical::GraphicalReportPrinter;
+pub use json::JsonReportPrinter;
+pub use narratable::NarratableReportPrinter;
+pub use theme::{GraphicalTheme, ThemeCharacters};
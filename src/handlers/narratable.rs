@@ -0,0 +1,73 @@
+use std::fmt::Write;
+
+use crate::{Diagnostic, MietteError};
+
+/// Renders a [`Diagnostic`] as plain, screen-reader-friendly prose instead
+/// of a boxed graphical layout. Useful for narrow terminals, logs, or
+/// accessibility tooling.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NarratableReportPrinter;
+
+impl NarratableReportPrinter {
+    /// Renders `diagnostic` into `out`.
+    pub fn render_report(
+        &self,
+        out: &mut impl Write,
+        diagnostic: &dyn Diagnostic,
+    ) -> Result<(), MietteError> {
+        writeln!(out, "{}", diagnostic)?;
+        if let Some(code) = diagnostic.code() {
+            writeln!(out, "    Diagnostic code: {}", code)?;
+        }
+        if let Some(snippets) = diagnostic.snippets() {
+            for snippet in snippets {
+                if let Some(message) = &snippet.message {
+                    writeln!(out, "Begin snippet for {} starting at {:?}: {}",
+                        snippet.source.name(), snippet.context.offset(), message)?;
+                } else {
+                    writeln!(out, "Begin snippet for {} starting at {:?}",
+                        snippet.source.name(), snippet.context.offset())?;
+                }
+                writeln!(out)?;
+
+                // The error is here; everything else is context -- so we
+                // describe primary highlights first, the way you'd narrate
+                // "here's the actual problem" before "...and here's why".
+                let mut highlights: Vec<_> = snippet.highlights.iter().collect();
+                highlights.sort_by_key(|h| !h.is_primary());
+                for highlight in highlights {
+                    let kind = if highlight.is_primary() {
+                        "this is the error"
+                    } else {
+                        "context"
+                    };
+                    if let Some(label) = &highlight.label {
+                        writeln!(
+                            out,
+                            "  {} at {:?} (len {}): {}",
+                            kind,
+                            highlight.span.offset(),
+                            highlight.span.len(),
+                            label
+                        )?;
+                    } else {
+                        writeln!(
+                            out,
+                            "  {} at {:?} (len {})",
+                            kind,
+                            highlight.span.offset(),
+                            highlight.span.len()
+                        )?;
+                    }
+                }
+            }
+        }
+        if let Some(help) = diagnostic.help() {
+            writeln!(out, "diagnostic help: {}", help)?;
+        }
+        if let Some(url) = diagnostic.url() {
+            writeln!(out, "diagnostic url: {}", url)?;
+        }
+        Ok(())
+    }
+}
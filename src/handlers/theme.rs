@@ -0,0 +1,106 @@
+/// The set of box-drawing / glyph characters a [`crate::GraphicalReportPrinter`]
+/// uses to draw gutters, underlines and arrows.
+#[derive(Clone, Debug)]
+pub struct ThemeCharacters {
+    pub hbar: char,
+    pub vbar: char,
+    pub vbar_break: char,
+
+    pub underline_primary: char,
+    pub underline_secondary: char,
+
+    pub underbar: char,
+    pub underline_left: char,
+
+    pub arrow: char,
+    pub top_left: char,
+    pub bottom_left: char,
+    pub mid_left: char,
+
+    pub error: char,
+    pub advice: char,
+    pub warning: char,
+}
+
+impl ThemeCharacters {
+    /// Fancy unicode box-drawing glyphs, the default.
+    pub fn unicode() -> Self {
+        Self {
+            hbar: '─',
+            vbar: '│',
+            vbar_break: '·',
+            underline_primary: '^',
+            underline_secondary: '─',
+            underbar: '┬',
+            underline_left: '╰',
+            arrow: '▶',
+            top_left: '╭',
+            bottom_left: '╰',
+            mid_left: '├',
+            error: '×',
+            advice: '☞',
+            warning: '⚠',
+        }
+    }
+
+    /// Plain-ASCII glyphs, for terminals without unicode support.
+    pub fn ascii() -> Self {
+        Self {
+            hbar: '-',
+            vbar: '|',
+            vbar_break: ':',
+            underline_primary: '^',
+            underline_secondary: '-',
+            underbar: '+',
+            underline_left: '`',
+            arrow: '>',
+            top_left: ',',
+            bottom_left: '`',
+            mid_left: '+',
+            error: 'x',
+            advice: '=',
+            warning: '!',
+        }
+    }
+}
+
+/// Controls the visual style ([`GraphicalReportPrinter`](crate::GraphicalReportPrinter)'s
+/// glyph set and whether output is colored.
+#[derive(Clone, Debug)]
+pub struct GraphicalTheme {
+    pub characters: ThemeCharacters,
+    pub with_color: bool,
+}
+
+impl GraphicalTheme {
+    /// Unicode glyphs, rendered with color.
+    pub fn unicode() -> Self {
+        Self {
+            characters: ThemeCharacters::unicode(),
+            with_color: true,
+        }
+    }
+
+    /// Unicode glyphs, with no color codes -- useful for snapshot tests
+    /// and other non-interactive output.
+    pub fn unicode_nocolor() -> Self {
+        Self {
+            characters: ThemeCharacters::unicode(),
+            with_color: false,
+        }
+    }
+
+    /// Plain ASCII glyphs, with no color codes.
+    pub fn ascii() -> Self {
+        Self {
+            characters: ThemeCharacters::ascii(),
+            with_color: false,
+        }
+    }
+}
+
+impl Default for GraphicalTheme {
+    fn default() -> Self {
+        Self::unicode()
+    }
+}
@@ -0,0 +1,39 @@
+//! miette is a diagnostic library for Rust, giving your errors nice,
+//! compiler-style reports complete with source snippets and highlights.
+
+mod error;
+mod handlers;
+mod named_source;
+mod protocol;
+mod suggestion;
+mod unicode_width;
+
+pub use error::MietteError;
+#[cfg(feature = "annotate-snippets")]
+pub use handlers::AnnotatedSnippetsPrinter;
+pub use handlers::{
+    GraphicalReportPrinter, GraphicalTheme, JsonReportPrinter, NarratableReportPrinter,
+    ThemeCharacters,
+};
+pub use miette_derive::Diagnostic;
+pub use named_source::{NamedSource, SourceSpan};
+pub use protocol::{Diagnostic, Highlight, HighlightKind, Severity, Snippet};
+pub use suggestion::{Applicability, Suggestion};
+
+/// A type-erased wrapper around any [`Diagnostic`], returned from
+/// `.into()` on a diagnostic-derived error so it can be handed to a
+/// report printer.
+pub struct DiagnosticReport(Box<dyn Diagnostic + Send + Sync + 'static>);
+
+impl DiagnosticReport {
+    /// Borrows the wrapped [`Diagnostic`].
+    pub fn inner(&self) -> &dyn Diagnostic {
+        self.0.as_ref()
+    }
+}
+
+impl<T: Diagnostic + Send + Sync + 'static> From<T> for DiagnosticReport {
+    fn from(diagnostic: T) -> Self {
+        Self(Box::new(diagnostic))
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+
+use crate::MietteError;
+
+/// A byte offset paired with a length, used to point at a region of a
+/// [`NamedSource`] (or any other source text).
+///
+/// Constructible from `(offset, length)` tuples for convenience at call
+/// sites, e.g. `(9, 4).into()`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SourceSpan {
+    offset: usize,
+    length: usize,
+}
+
+impl SourceSpan {
+    /// Creates a new `SourceSpan`.
+    pub fn new(offset: usize, length: usize) -> Self {
+        Self { offset, length }
+    }
+
+    /// The byte offset of the start of this span.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The length, in bytes, of this span.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// True if this span covers no bytes at all.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+impl From<(usize, usize)> for SourceSpan {
+    fn from((offset, length): (usize, usize)) -> Self {
+        Self::new(offset, length)
+    }
+}
+
+/// A named chunk of source text, such as a source file, that diagnostics
+/// can point into with [`SourceSpan`]s.
+#[derive(Clone, Debug)]
+pub struct NamedSource {
+    name: String,
+    source: String,
+}
+
+impl NamedSource {
+    /// Creates a new `NamedSource` from a name and its source text.
+    pub fn new(name: impl AsRef<str>, source: String) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            source,
+        }
+    }
+
+    /// The name this source was registered under (usually a file path).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The full underlying source text.
+    pub fn inner(&self) -> &str {
+        &self.source
+    }
+
+    /// Slices out the text covered by `span`, erroring if it's out of
+    /// bounds for this source.
+    pub fn read_span(&self, span: &SourceSpan) -> Result<&str, MietteError> {
+        self.source
+            .get(span.offset()..span.offset() + span.len())
+            .ok_or(MietteError::OutOfBounds)
+    }
+}
+
+impl fmt::Display for NamedSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
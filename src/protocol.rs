@@ -0,0 +1,117 @@
+use crate::{NamedSource, SourceSpan, Suggestion};
+
+/// How severe a diagnostic is, mirroring the levels a compiler would use.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    /// Things aren't quite right, but the program can keep going.
+    Advice,
+    /// Something that deserves a second look, but isn't fatal.
+    Warning,
+    /// Something went wrong. This is the default.
+    Error,
+}
+
+/// Whether a [`Highlight`] is the actual location of the problem, or just
+/// supporting context for it.
+///
+/// This mirrors rustc's `MultiSpan` model: a diagnostic usually has one
+/// primary span (where the error *is*) and zero or more secondary spans
+/// (surrounding context that helps explain it). Printers use this to pick
+/// different glyphs and ordering for each kind.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum HighlightKind {
+    /// The actual location of the problem.
+    Primary,
+    /// Supporting context for the problem.
+    #[default]
+    Secondary,
+}
+
+/// A single highlighted region within a [`Snippet`], with an optional
+/// label describing what's being pointed at.
+#[derive(Clone, Debug)]
+pub struct Highlight {
+    /// The span being highlighted.
+    pub span: SourceSpan,
+    /// A short label rendered next to the underline, if any.
+    pub label: Option<String>,
+    /// Whether this is the diagnostic's primary span or just context.
+    pub kind: HighlightKind,
+}
+
+impl Highlight {
+    /// Creates a new secondary highlight. Use [`Highlight::primary`] for
+    /// the diagnostic's actual location.
+    pub fn new(span: SourceSpan, label: Option<String>) -> Self {
+        Self {
+            span,
+            label,
+            kind: HighlightKind::Secondary,
+        }
+    }
+
+    /// Creates a new primary highlight.
+    pub fn primary(span: SourceSpan, label: Option<String>) -> Self {
+        Self {
+            span,
+            label,
+            kind: HighlightKind::Primary,
+        }
+    }
+
+    /// True if this is the diagnostic's primary span.
+    pub fn is_primary(&self) -> bool {
+        self.kind == HighlightKind::Primary
+    }
+}
+
+/// A chunk of source code, annotated with one or more [`Highlight`]s, to
+/// be rendered as part of a diagnostic.
+#[derive(Clone, Debug)]
+pub struct Snippet<'a> {
+    /// The source this snippet's spans are measured against.
+    pub source: &'a NamedSource,
+    /// A short message describing what's wrong with this snippet.
+    pub message: Option<String>,
+    /// The region of `source` this snippet focuses on.
+    pub context: SourceSpan,
+    /// The individual highlights within `context`.
+    pub highlights: Vec<Highlight>,
+}
+
+/// The main trait implemented (usually via `#[derive(Diagnostic)]`) by
+/// miette's error types, describing how a diagnostic should be rendered.
+pub trait Diagnostic: std::error::Error {
+    /// A unique, machine-readable code identifying this diagnostic, e.g.
+    /// `oops::my::bad`.
+    fn code(&self) -> Option<Box<dyn std::fmt::Display + '_>> {
+        None
+    }
+
+    /// How severe this diagnostic is. Defaults to [`Severity::Error`].
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Additional help text suggesting how to fix the problem.
+    fn help(&self) -> Option<Box<dyn std::fmt::Display + '_>> {
+        None
+    }
+
+    /// A URL with more information about this diagnostic's `code`.
+    fn url(&self) -> Option<Box<dyn std::fmt::Display + '_>> {
+        None
+    }
+
+    /// The snippets of source code this diagnostic points into.
+    fn snippets(&self) -> Option<Box<dyn Iterator<Item = Snippet<'_>> + '_>> {
+        None
+    }
+
+    /// Structured fix-its for this diagnostic, if any. Unlike `help()`,
+    /// these carry an exact span and replacement text, so a tool can
+    /// apply them without a human re-typing the fix.
+    fn suggestions(&self) -> Vec<Suggestion<'_>> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{NamedSource, SourceSpan};
+
+/// How safe a [`Suggestion`] is to apply without a human reviewing it
+/// first, mirroring rustc's `Applicability` for `CodeSuggestion`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended; a tool can
+    /// apply it automatically.
+    MachineApplicable,
+    /// The suggestion may be incorrect and should be reviewed before
+    /// applying.
+    MaybeIncorrect,
+    /// The suggestion contains placeholders the user needs to fill in,
+    /// like `/* value */`.
+    HasPlaceholders,
+    /// No claim is made about how safe this suggestion is to apply.
+    Unspecified,
+}
+
+/// A structured fix-it: a span to replace and the text to replace it
+/// with, along with how safe that replacement is to apply automatically.
+///
+/// This is what lets a tool (e.g. a `cargo fix`-style auto-applier) patch
+/// up a diagnosed problem instead of a human having to read free-text
+/// `help(...)` and type the fix themselves.
+#[derive(Clone, Debug)]
+pub struct Suggestion<'a> {
+    /// The source `span` is measured against -- not necessarily the same
+    /// source as any of the diagnostic's snippets.
+    pub source: &'a NamedSource,
+    /// The span of source text to be replaced.
+    pub span: SourceSpan,
+    /// The text that should replace it.
+    pub replacement: String,
+    /// How safe this suggestion is to apply without review.
+    pub applicability: Applicability,
+}
+
+impl<'a> Suggestion<'a> {
+    /// Creates a new suggestion.
+    pub fn new(
+        source: &'a NamedSource,
+        span: SourceSpan,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        Self {
+            source,
+            span,
+            replacement: replacement.into(),
+            applicability,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+/// The number of terminal cells a character occupies, the way a
+/// monospace terminal emulator would draw it.
+///
+/// This is a deliberately small stand-in for the `unicode-width` crate's
+/// `UnicodeWidthChar::width`: CJK ideographs and other "fullwidth" glyphs
+/// take two cells, combining marks take zero (they're drawn stacked on
+/// the previous cell), and everything else takes one. Byte length alone
+/// can't tell you any of this -- a single CJK character is 3 UTF-8 bytes
+/// but exactly one *grapheme*, rendered in two display columns.
+pub fn char_width(c: char) -> usize {
+    let cp = c as u32;
+    if is_zero_width(cp) {
+        0
+    } else if is_wide(cp) {
+        2
+    } else {
+        1
+    }
+}
+
+/// The display width of a string: the sum of its characters' widths, not
+/// its byte length or `chars().count()`.
+pub fn str_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+fn is_zero_width(cp: u32) -> bool {
+    matches!(
+        cp,
+        0x0300..=0x036F   // combining diacritical marks
+        | 0x200B..=0x200D // zero-width space/non-joiners
+        | 0xFE00..=0xFE0F // variation selectors
+        | 0x1AB0..=0x1AFF // combining diacritical marks extended
+    )
+}
+
+fn is_wide(cp: u32) -> bool {
+    matches!(
+        cp,
+        0x1100..=0x115F   // Hangul Jamo
+        | 0x2E80..=0x303E // CJK radicals / symbols and punctuation
+        | 0x3041..=0x33FF // Hiragana, Katakana, CJK compat
+        | 0x3400..=0x4DBF // CJK extension A
+        | 0x4E00..=0x9FFF // CJK unified ideographs
+        | 0xA000..=0xA4CF // Yi syllables
+        | 0xAC00..=0xD7A3 // Hangul syllables
+        | 0xF900..=0xFAFF // CJK compatibility ideographs
+        | 0xFF00..=0xFF60 // fullwidth forms
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1FAFF // emoji blocks
+        | 0x20000..=0x3FFFD // CJK extension B and beyond
+    )
+}